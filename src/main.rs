@@ -4,8 +4,11 @@ use std::{
     time::{Duration, Instant},
 };
 
+use post::PostPass;
 use rand::prelude::*;
-use vertex::{create_vertex_buffer, get_vertex_buffer_layout, VERTICES};
+use vertex::{
+    create_index_buffer, create_vertex_buffer, get_vertex_buffer_layout, INDICES, VERTICES,
+};
 use wgpu::{
     core::binding_model::BindGroupLayout,
     include_wgsl,
@@ -14,23 +17,256 @@ use wgpu::{
     BindGroupLayoutEntry, BindingResource, BindingType, BlendState, Buffer, BufferDescriptor,
     BufferUsages, Color, ColorTargetState, ColorWrites, CommandEncoderDescriptor,
     ComputePassDescriptor, ComputePipeline, ComputePipelineDescriptor, Device, DeviceDescriptor,
-    FragmentState, Instance, InstanceDescriptor, MultisampleState, Operations,
+    Extent3d, FilterMode, FragmentState, ImageCopyBuffer, ImageDataLayout, Instance,
+    InstanceDescriptor, Maintain, MapMode, MultisampleState, Operations,
     PipelineCompilationOptions, PipelineLayout, PipelineLayoutDescriptor, PrimitiveState, Queue,
     RenderPassColorAttachment, RenderPassDescriptor, RenderPipeline, RenderPipelineDescriptor,
-    RequestAdapterOptions, ShaderStages, Surface, SurfaceConfiguration, SurfaceError,
-    TextureUsages, TextureViewDescriptor, VertexState,
+    RequestAdapterOptions, SamplerDescriptor, ShaderStages, Surface, SurfaceConfiguration,
+    SurfaceError, Texture, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+    TextureView, TextureViewDescriptor, VertexState,
 };
 use winit::{
     application::ApplicationHandler,
-    dpi::PhysicalSize,
-    event::{ElementState, KeyEvent, MouseButton, WindowEvent},
+    dpi::{PhysicalPosition, PhysicalSize},
+    event::{ElementState, KeyEvent, MouseButton, MouseScrollDelta, WindowEvent},
     event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
     keyboard::{Key, NamedKey},
     window::{Window, WindowId},
 };
 
+mod post;
 mod vertex;
 
+const MIN_ZOOM: f32 = 0.1;
+const MAX_ZOOM: f32 = 10.0;
+const ZOOM_SPEED: f32 = 0.1;
+
+const BLOOM_THRESHOLD: f32 = 0.6;
+const BLOOM_RADIUS: f32 = 1.5;
+const CRT_SCANLINE_STRENGTH: f32 = 0.3;
+const CRT_CURVATURE: f32 = 0.15;
+
+#[derive(Copy, Clone)]
+struct Camera {
+    offset: [f32; 2],
+    zoom: f32,
+}
+
+impl Camera {
+    fn to_uniform(&self) -> CameraUniform {
+        CameraUniform {
+            offset: self.offset,
+            zoom: self.zoom,
+            _padding: 0.0,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct CameraUniform {
+    offset: [f32; 2],
+    zoom: f32,
+    _padding: f32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct BloomParams {
+    threshold: f32,
+    radius: f32,
+    direction: [f32; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct CrtParams {
+    scanline_strength: f32,
+    curvature: f32,
+    _padding: [f32; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct GridUniform {
+    size: [f32; 2],
+    survive_mask: u32,
+    birth_mask: u32,
+}
+
+/// A B/S ruleset expressed as bitmasks over live-neighbor counts (bit `n` set means
+/// "this applies when a cell has `n` live neighbors"), so non-standard rules can be run
+/// without recompiling the compute shader.
+#[derive(Copy, Clone, Debug)]
+struct Rule {
+    survive: u32,
+    birth: u32,
+}
+
+impl Rule {
+    /// Conway's standard rule: B3/S23.
+    const CONWAY: Self = Self {
+        survive: 0b0000_1100,
+        birth: 0b0000_1000,
+    };
+}
+
+/// Parameters for the simulation that used to be hard-coded in `State::new`, lifted out
+/// so different grid sizes, seed densities, and rulesets can be tried without recompiling.
+#[derive(Clone)]
+struct SimulationConfig {
+    grid_size: usize,
+    seed_density: f32,
+    compute_delay: Duration,
+    rule: Rule,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            grid_size: 512,
+            seed_density: 0.6,
+            compute_delay: Duration::from_millis(8),
+            rule: Rule::CONWAY,
+        }
+    }
+}
+
+/// Wires up the bright-pass -> horizontal blur -> vertical blur -> composite -> CRT chain
+/// against the given set of intermediate textures. Called from `State::new` and again from
+/// `State::resize`, since the intermediate textures (and therefore their bind groups) are
+/// sized to the window.
+#[allow(clippy::too_many_arguments)]
+fn build_post_passes(
+    device: &Device,
+    bright_pipeline: &RenderPipeline,
+    blur_pipeline: &RenderPipeline,
+    composite_pipeline: &RenderPipeline,
+    crt_pipeline: &RenderPipeline,
+    filter_layout: &wgpu::BindGroupLayout,
+    composite_layout: &wgpu::BindGroupLayout,
+    sampler: &wgpu::Sampler,
+    offscreen_view: &TextureView,
+    bright_view: &TextureView,
+    blur_h_view: &TextureView,
+    blur_v_view: &TextureView,
+    composite_view: &TextureView,
+    bloom_params_buffer: &Buffer,
+    blur_h_params_buffer: &Buffer,
+    blur_v_params_buffer: &Buffer,
+    crt_params_buffer: &Buffer,
+) -> Vec<PostPass> {
+    vec![
+        PostPass {
+            pipeline: bright_pipeline.clone(),
+            bind_group: post::create_filter_bind_group(
+                device,
+                filter_layout,
+                offscreen_view,
+                sampler,
+                bloom_params_buffer,
+            ),
+            target: Some(bright_view.clone()),
+            enabled: true,
+        },
+        PostPass {
+            pipeline: blur_pipeline.clone(),
+            bind_group: post::create_filter_bind_group(
+                device,
+                filter_layout,
+                bright_view,
+                sampler,
+                blur_h_params_buffer,
+            ),
+            target: Some(blur_h_view.clone()),
+            enabled: true,
+        },
+        PostPass {
+            pipeline: blur_pipeline.clone(),
+            bind_group: post::create_filter_bind_group(
+                device,
+                filter_layout,
+                blur_h_view,
+                sampler,
+                blur_v_params_buffer,
+            ),
+            target: Some(blur_v_view.clone()),
+            enabled: true,
+        },
+        PostPass {
+            pipeline: composite_pipeline.clone(),
+            bind_group: post::create_composite_bind_group(
+                device,
+                composite_layout,
+                offscreen_view,
+                blur_v_view,
+                sampler,
+            ),
+            target: Some(composite_view.clone()),
+            enabled: true,
+        },
+        PostPass {
+            pipeline: crt_pipeline.clone(),
+            bind_group: post::create_filter_bind_group(
+                device,
+                filter_layout,
+                composite_view,
+                sampler,
+                crt_params_buffer,
+            ),
+            target: None,
+            enabled: true,
+        },
+    ]
+}
+
+const GRADIENT_STEPS: u32 = 256;
+const GRADIENT_YOUNG: [f32; 3] = [1.0, 0.35, 0.12];
+const GRADIENT_OLD: [f32; 3] = [0.16, 0.22, 0.6];
+
+/// Builds the 1D age→color lookup texture: hot near-orange for freshly born cells,
+/// cooling toward a deep blue for cells that have survived many generations.
+fn create_gradient_texture(device: &Device, queue: &Queue) -> (Texture, TextureView) {
+    let mut data = Vec::with_capacity((GRADIENT_STEPS * 4) as usize);
+    for i in 0..GRADIENT_STEPS {
+        let t = i as f32 / (GRADIENT_STEPS - 1) as f32;
+        for (young, old) in GRADIENT_YOUNG.iter().zip(GRADIENT_OLD) {
+            let value = young + (old - young) * t;
+            data.push((value * 255.0) as u8);
+        }
+        data.push(255);
+    }
+
+    let size = Extent3d {
+        width: GRADIENT_STEPS,
+        height: 1,
+        depth_or_array_layers: 1,
+    };
+    let texture = device.create_texture(&TextureDescriptor {
+        label: None,
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D1,
+        format: TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    queue.write_texture(
+        texture.as_image_copy(),
+        &data,
+        ImageDataLayout {
+            offset: 0,
+            bytes_per_row: Some(GRADIENT_STEPS * 4),
+            rows_per_image: None,
+        },
+        size,
+    );
+
+    let view = texture.create_view(&TextureViewDescriptor::default());
+    (texture, view)
+}
+
 fn initialise_webgpu<'a>(
     window: Arc<Window>,
 ) -> Result<(Surface<'a>, SurfaceConfiguration, Device, Queue), StateError> {
@@ -78,6 +314,12 @@ enum StateError {
     RequestDeviceError(#[from] wgpu::RequestDeviceError),
     #[error("No adapter was found")]
     NoAdapterFound,
+    #[error("Failed to write screenshot: {0}")]
+    ImageError(#[from] image::ImageError),
+    #[error("Failed to map screenshot readback buffer: {0}")]
+    BufferMapError(#[from] wgpu::BufferAsyncError),
+    #[error("Screenshot readback channel was dropped before completion")]
+    ReadbackChannelClosed,
 }
 
 struct State<'a> {
@@ -90,9 +332,35 @@ struct State<'a> {
     queue: Queue,
     // Render pipeline
     vertex_buffer: Buffer,
+    index_buffer: Buffer,
+    cell_states_buffers: [Buffer; 2],
     bind_groups: [BindGroup; 2],
     cell_pipeline: RenderPipeline,
     simulation_pipeline: ComputePipeline,
+    // Camera
+    camera: Camera,
+    camera_buffer: Buffer,
+    dragging: bool,
+    last_cursor_pos: Option<PhysicalPosition<f64>>,
+    // Drawing
+    painting: Option<f32>,
+    paused: bool,
+    // Post-processing
+    offscreen_view: TextureView,
+    bright_view: TextureView,
+    blur_h_view: TextureView,
+    blur_v_view: TextureView,
+    composite_view: TextureView,
+    post_sampler: wgpu::Sampler,
+    bright_pipeline: RenderPipeline,
+    blur_pipeline: RenderPipeline,
+    composite_pipeline: RenderPipeline,
+    crt_pipeline: RenderPipeline,
+    bloom_params_buffer: Buffer,
+    blur_h_params_buffer: Buffer,
+    blur_v_params_buffer: Buffer,
+    crt_params_buffer: Buffer,
+    post_passes: Vec<PostPass>,
     // Other
     size: PhysicalSize<u32>,
     clear_color: Color,
@@ -103,22 +371,28 @@ struct State<'a> {
 }
 
 impl<'a> State<'a> {
-    fn new(window: Arc<Window>) -> Result<Self, StateError> {
+    fn new(window: Arc<Window>, config: SimulationConfig) -> Result<Self, StateError> {
         let size = window.inner_size();
-        let grid_size = 512;
+        let grid_size = config.grid_size;
 
         let (surface, surface_config, device, queue) = initialise_webgpu(window.clone())?;
 
         let vertex_buffer =
             create_vertex_buffer(&device, (VERTICES.len() * std::mem::size_of::<f32>()) as _);
         queue.write_buffer(&vertex_buffer, 0, bytemuck::cast_slice(&VERTICES));
+        let index_buffer =
+            create_index_buffer(&device, (INDICES.len() * std::mem::size_of::<u16>()) as _);
+        queue.write_buffer(&index_buffer, 0, bytemuck::cast_slice(&INDICES));
 
         let vertex_buffer_layout = get_vertex_buffer_layout();
 
-        let uniform_array = [grid_size as f32; 2];
         let uniform_buffer = device.create_buffer_init(&BufferInitDescriptor {
             label: None,
-            contents: bytemuck::cast_slice(&uniform_array),
+            contents: bytemuck::cast_slice(&[GridUniform {
+                size: [grid_size as f32; 2],
+                survive_mask: config.rule.survive,
+                birth_mask: config.rule.birth,
+            }]),
             usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
         });
 
@@ -139,8 +413,12 @@ impl<'a> State<'a> {
         ];
 
         let mut rng = rand::thread_rng();
-        for i in 0..cell_states.len() {
-            cell_states[i] = if rng.gen::<f32>() > 0.6 { 1.0 } else { 0.0 };
+        for cell in cell_states.iter_mut() {
+            *cell = if rng.gen::<f32>() < config.seed_density {
+                1.0
+            } else {
+                0.0
+            };
         }
         queue.write_buffer(
             &cell_states_buffers[0],
@@ -148,6 +426,44 @@ impl<'a> State<'a> {
             bytemuck::cast_slice(&cell_states),
         );
 
+        let age_buffers = [
+            device.create_buffer(&BufferDescriptor {
+                label: None,
+                size: (cell_states.len() * std::mem::size_of::<u32>()) as _,
+                usage: BufferUsages::STORAGE | BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+            device.create_buffer(&BufferDescriptor {
+                label: None,
+                size: (cell_states.len() * std::mem::size_of::<u32>()) as _,
+                usage: BufferUsages::STORAGE | BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+        ];
+        queue.write_buffer(
+            &age_buffers[0],
+            0,
+            bytemuck::cast_slice(&vec![0u32; cell_states.len()]),
+        );
+
+        let (_, gradient_view) = create_gradient_texture(&device, &queue);
+        let gradient_sampler = device.create_sampler(&SamplerDescriptor {
+            label: None,
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let camera = Camera {
+            offset: [0.0, 0.0],
+            zoom: 1.0,
+        };
+        let camera_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: None,
+            contents: bytemuck::cast_slice(&[camera.to_uniform()]),
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        });
+
         let shader = device.create_shader_module(include_wgsl!("shader.wgsl"));
         let simulator = device.create_shader_module(include_wgsl!("compute.wgsl"));
 
@@ -184,6 +500,52 @@ impl<'a> State<'a> {
                     },
                     count: None,
                 },
+                BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: ShaderStages::VERTEX,
+                    ty: BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: ShaderStages::VERTEX | ShaderStages::COMPUTE,
+                    ty: BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: ShaderStages::COMPUTE,
+                    ty: BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D1,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 7,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
             ],
         });
         let bind_groups = [
@@ -209,6 +571,32 @@ impl<'a> State<'a> {
                             cell_states_buffers[1].as_entire_buffer_binding(),
                         ),
                     },
+                    BindGroupEntry {
+                        binding: 3,
+                        resource: BindingResource::Buffer(
+                            camera_buffer.as_entire_buffer_binding(),
+                        ),
+                    },
+                    BindGroupEntry {
+                        binding: 4,
+                        resource: BindingResource::Buffer(
+                            age_buffers[0].as_entire_buffer_binding(),
+                        ),
+                    },
+                    BindGroupEntry {
+                        binding: 5,
+                        resource: BindingResource::Buffer(
+                            age_buffers[1].as_entire_buffer_binding(),
+                        ),
+                    },
+                    BindGroupEntry {
+                        binding: 6,
+                        resource: BindingResource::TextureView(&gradient_view),
+                    },
+                    BindGroupEntry {
+                        binding: 7,
+                        resource: BindingResource::Sampler(&gradient_sampler),
+                    },
                 ],
             }),
             device.create_bind_group(&BindGroupDescriptor {
@@ -233,6 +621,32 @@ impl<'a> State<'a> {
                             cell_states_buffers[0].as_entire_buffer_binding(),
                         ),
                     },
+                    BindGroupEntry {
+                        binding: 3,
+                        resource: BindingResource::Buffer(
+                            camera_buffer.as_entire_buffer_binding(),
+                        ),
+                    },
+                    BindGroupEntry {
+                        binding: 4,
+                        resource: BindingResource::Buffer(
+                            age_buffers[1].as_entire_buffer_binding(),
+                        ),
+                    },
+                    BindGroupEntry {
+                        binding: 5,
+                        resource: BindingResource::Buffer(
+                            age_buffers[0].as_entire_buffer_binding(),
+                        ),
+                    },
+                    BindGroupEntry {
+                        binding: 6,
+                        resource: BindingResource::TextureView(&gradient_view),
+                    },
+                    BindGroupEntry {
+                        binding: 7,
+                        resource: BindingResource::Sampler(&gradient_sampler),
+                    },
                 ],
             }),
         ];
@@ -274,6 +688,111 @@ impl<'a> State<'a> {
             compilation_options: Default::default(),
         });
 
+        let (_, offscreen_view) =
+            post::create_color_texture(&device, size.width, size.height, surface_config.format);
+        let (_, bright_view) =
+            post::create_color_texture(&device, size.width, size.height, surface_config.format);
+        let (_, blur_h_view) =
+            post::create_color_texture(&device, size.width, size.height, surface_config.format);
+        let (_, blur_v_view) =
+            post::create_color_texture(&device, size.width, size.height, surface_config.format);
+        let (_, composite_view) =
+            post::create_color_texture(&device, size.width, size.height, surface_config.format);
+
+        let post_sampler = post::create_post_sampler(&device);
+
+        let bloom_params_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: None,
+            contents: bytemuck::cast_slice(&[BloomParams {
+                threshold: BLOOM_THRESHOLD,
+                radius: BLOOM_RADIUS,
+                direction: [0.0, 0.0],
+            }]),
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        });
+        let blur_h_params_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: None,
+            contents: bytemuck::cast_slice(&[BloomParams {
+                threshold: BLOOM_THRESHOLD,
+                radius: BLOOM_RADIUS,
+                direction: [1.0, 0.0],
+            }]),
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        });
+        let blur_v_params_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: None,
+            contents: bytemuck::cast_slice(&[BloomParams {
+                threshold: BLOOM_THRESHOLD,
+                radius: BLOOM_RADIUS,
+                direction: [0.0, 1.0],
+            }]),
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        });
+        let crt_params_buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: None,
+            contents: bytemuck::cast_slice(&[CrtParams {
+                scanline_strength: CRT_SCANLINE_STRENGTH,
+                curvature: CRT_CURVATURE,
+                _padding: [0.0, 0.0],
+            }]),
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        });
+
+        let bloom_shader = device.create_shader_module(include_wgsl!("post_bloom.wgsl"));
+        let crt_shader = device.create_shader_module(include_wgsl!("post_crt.wgsl"));
+
+        let filter_layout = post::create_filter_bind_group_layout(&device);
+        let composite_layout = post::create_composite_bind_group_layout(&device);
+
+        let bright_pipeline = post::create_filter_pipeline(
+            &device,
+            &bloom_shader,
+            "brightPassMain",
+            &filter_layout,
+            surface_config.format,
+        );
+        let blur_pipeline = post::create_filter_pipeline(
+            &device,
+            &bloom_shader,
+            "blurMain",
+            &filter_layout,
+            surface_config.format,
+        );
+        let composite_pipeline = post::create_filter_pipeline(
+            &device,
+            &bloom_shader,
+            "compositeMain",
+            &composite_layout,
+            surface_config.format,
+        );
+        let crt_pipeline = post::create_filter_pipeline(
+            &device,
+            &crt_shader,
+            "crtMain",
+            &filter_layout,
+            surface_config.format,
+        );
+
+        let post_passes = build_post_passes(
+            &device,
+            &bright_pipeline,
+            &blur_pipeline,
+            &composite_pipeline,
+            &crt_pipeline,
+            &filter_layout,
+            &composite_layout,
+            &post_sampler,
+            &offscreen_view,
+            &bright_view,
+            &blur_h_view,
+            &blur_v_view,
+            &composite_view,
+            &bloom_params_buffer,
+            &blur_h_params_buffer,
+            &blur_v_params_buffer,
+            &crt_params_buffer,
+        );
+
         Ok(Self {
             surface,
             surface_config,
@@ -288,13 +807,36 @@ impl<'a> State<'a> {
                 a: 1.0,
             },
             vertex_buffer,
+            index_buffer,
+            cell_states_buffers,
             cell_pipeline,
             simulation_pipeline,
             grid_size,
             bind_groups,
+            camera,
+            camera_buffer,
+            dragging: false,
+            last_cursor_pos: None,
+            painting: None,
+            paused: false,
+            offscreen_view,
+            bright_view,
+            blur_h_view,
+            blur_v_view,
+            composite_view,
+            post_sampler,
+            bright_pipeline,
+            blur_pipeline,
+            composite_pipeline,
+            crt_pipeline,
+            bloom_params_buffer,
+            blur_h_params_buffer,
+            blur_v_params_buffer,
+            crt_params_buffer,
+            post_passes,
             selected_bind: 0,
             last_time: Instant::now(),
-            compute_delay: Duration::from_millis(8),
+            compute_delay: config.compute_delay,
         })
     }
 
@@ -303,13 +845,156 @@ impl<'a> State<'a> {
         self.surface_config.width = new_size.width;
         self.surface_config.height = new_size.height;
         self.surface.configure(&self.device, &self.surface_config);
+
+        let format = self.surface_config.format;
+        (_, self.offscreen_view) =
+            post::create_color_texture(&self.device, new_size.width, new_size.height, format);
+        (_, self.bright_view) =
+            post::create_color_texture(&self.device, new_size.width, new_size.height, format);
+        (_, self.blur_h_view) =
+            post::create_color_texture(&self.device, new_size.width, new_size.height, format);
+        (_, self.blur_v_view) =
+            post::create_color_texture(&self.device, new_size.width, new_size.height, format);
+        (_, self.composite_view) =
+            post::create_color_texture(&self.device, new_size.width, new_size.height, format);
+
+        let filter_layout = self.bright_pipeline.get_bind_group_layout(0);
+        let composite_layout = self.composite_pipeline.get_bind_group_layout(0);
+        self.post_passes = build_post_passes(
+            &self.device,
+            &self.bright_pipeline,
+            &self.blur_pipeline,
+            &self.composite_pipeline,
+            &self.crt_pipeline,
+            &filter_layout,
+            &composite_layout,
+            &self.post_sampler,
+            &self.offscreen_view,
+            &self.bright_view,
+            &self.blur_h_view,
+            &self.blur_v_view,
+            &self.composite_view,
+            &self.bloom_params_buffer,
+            &self.blur_h_params_buffer,
+            &self.blur_v_params_buffer,
+            &self.crt_params_buffer,
+        );
     }
 
     fn input(&mut self, event: &WindowEvent) -> bool {
-        false
+        match event {
+            WindowEvent::MouseWheel { delta, .. } => {
+                let scroll = match delta {
+                    MouseScrollDelta::LineDelta(_, y) => *y,
+                    MouseScrollDelta::PixelDelta(pos) => pos.y as f32 / 100.0,
+                };
+                self.camera.zoom = (self.camera.zoom + scroll * ZOOM_SPEED).clamp(MIN_ZOOM, MAX_ZOOM);
+                true
+            }
+            WindowEvent::MouseInput {
+                state,
+                button: MouseButton::Middle,
+                ..
+            } => {
+                self.dragging = *state == ElementState::Pressed;
+                true
+            }
+            WindowEvent::MouseInput {
+                state,
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.painting = (*state == ElementState::Pressed).then_some(1.0);
+                if let (Some(value), Some(position)) = (self.painting, self.last_cursor_pos) {
+                    self.paint_cell(position, value);
+                }
+                true
+            }
+            WindowEvent::MouseInput {
+                state,
+                button: MouseButton::Right,
+                ..
+            } => {
+                self.painting = (*state == ElementState::Pressed).then_some(0.0);
+                if let (Some(value), Some(position)) = (self.painting, self.last_cursor_pos) {
+                    self.paint_cell(position, value);
+                }
+                true
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                if self.dragging {
+                    if let Some(last) = self.last_cursor_pos {
+                        let dx = (position.x - last.x) as f32;
+                        let dy = (position.y - last.y) as f32;
+                        self.camera.offset[0] += dx / self.size.width as f32 * 2.0;
+                        self.camera.offset[1] -= dy / self.size.height as f32 * 2.0;
+                    }
+                }
+                if let Some(value) = self.painting {
+                    self.paint_cell(*position, value);
+                }
+                self.last_cursor_pos = Some(*position);
+                true
+            }
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        logical_key: Key::Named(NamedKey::Space),
+                        state: ElementState::Pressed,
+                        repeat: false,
+                        ..
+                    },
+                ..
+            } => {
+                self.paused = !self.paused;
+                true
+            }
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        logical_key: Key::Named(NamedKey::F12),
+                        state: ElementState::Pressed,
+                        repeat: false,
+                        ..
+                    },
+                ..
+            } => {
+                if let Err(err) = self.capture_screenshot("screenshot.png") {
+                    eprintln!("{err}");
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Inverts the surface/camera mapping to find the grid cell under a cursor position,
+    /// or `None` if it falls outside the grid.
+    fn screen_to_cell(&self, position: PhysicalPosition<f64>) -> Option<(usize, usize)> {
+        pick_cell(position, self.size, self.camera, self.grid_size)
+    }
+
+    /// Writes a single cell state into the source buffer of the active `selected_bind`,
+    /// so the next compute step picks up the edit.
+    fn paint_cell(&self, position: PhysicalPosition<f64>, value: f32) {
+        let Some((col, row)) = self.screen_to_cell(position) else {
+            return;
+        };
+
+        let index = row * self.grid_size + col;
+        let offset = (index * std::mem::size_of::<f32>()) as u64;
+        self.queue.write_buffer(
+            &self.cell_states_buffers[self.selected_bind],
+            offset,
+            bytemuck::cast_slice(&[value]),
+        );
     }
 
     fn update(&mut self) {
+        if self.paused {
+            return;
+        }
+
         let now = Instant::now();
         if now - self.last_time < self.compute_delay {
             return;
@@ -337,6 +1022,12 @@ impl<'a> State<'a> {
     }
 
     fn render(&mut self) -> Result<(), SurfaceError> {
+        self.queue.write_buffer(
+            &self.camera_buffer,
+            0,
+            bytemuck::cast_slice(&[self.camera.to_uniform()]),
+        );
+
         let output = self.surface.get_current_texture()?;
         let view = output
             .texture
@@ -350,7 +1041,7 @@ impl<'a> State<'a> {
             #[allow(unused_mut, unused_variables)]
             let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
                 color_attachments: &[Some(RenderPassColorAttachment {
-                    view: &view,
+                    view: &self.offscreen_view,
                     resolve_target: None,
                     ops: Operations {
                         load: wgpu::LoadOp::Clear(self.clear_color),
@@ -362,22 +1053,212 @@ impl<'a> State<'a> {
 
             render_pass.set_pipeline(&self.cell_pipeline);
             render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+            render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
             render_pass.set_bind_group(0, &self.bind_groups[self.selected_bind], &[]);
-            render_pass.draw(
-                0..(VERTICES.len() as u32 / 2),
+            render_pass.draw_indexed(
+                0..(INDICES.len() as u32),
+                0,
                 0..(self.grid_size * self.grid_size) as _,
             );
         }
 
+        for pass in &self.post_passes {
+            if !pass.enabled {
+                continue;
+            }
+
+            let target = pass.target.as_ref().unwrap_or(&view);
+            let mut post_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: wgpu::LoadOp::Clear(Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                ..Default::default()
+            });
+
+            post_pass.set_pipeline(&pass.pipeline);
+            post_pass.set_bind_group(0, &pass.bind_group, &[]);
+            post_pass.draw(0..3, 0..1);
+        }
+
         self.queue.submit([encoder.finish()]);
         output.present();
 
         Ok(())
     }
+
+    /// Renders the cell pipeline and the full post-processing chain fresh into a `COPY_SRC`
+    /// offscreen texture (rather than sampling whatever the surface last presented) and reads
+    /// it back to disk as a PNG.
+    fn capture_screenshot(&self, path: &str) -> Result<(), StateError> {
+        let width = self.size.width;
+        let height = self.size.height;
+        let format = self.surface_config.format;
+
+        let capture_texture = self.device.create_texture(&TextureDescriptor {
+            label: None,
+            size: Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format,
+            usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let capture_view = capture_texture.create_view(&TextureViewDescriptor::default());
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&CommandEncoderDescriptor::default());
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &self.offscreen_view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: wgpu::LoadOp::Clear(self.clear_color),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                ..Default::default()
+            });
+
+            render_pass.set_pipeline(&self.cell_pipeline);
+            render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+            render_pass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+            render_pass.set_bind_group(0, &self.bind_groups[self.selected_bind], &[]);
+            render_pass.draw_indexed(
+                0..(INDICES.len() as u32),
+                0,
+                0..(self.grid_size * self.grid_size) as _,
+            );
+        }
+
+        for pass in &self.post_passes {
+            if !pass.enabled {
+                continue;
+            }
+
+            let target = pass.target.as_ref().unwrap_or(&capture_view);
+            let mut post_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: wgpu::LoadOp::Clear(Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                ..Default::default()
+            });
+
+            post_pass.set_pipeline(&pass.pipeline);
+            post_pass.set_bind_group(0, &pass.bind_group, &[]);
+            post_pass.draw(0..3, 0..1);
+        }
+
+        let unpadded_bytes_per_row = width * 4;
+        let padded_bytes_per_row = align_up(unpadded_bytes_per_row, 256);
+        let readback_buffer = self.device.create_buffer(&BufferDescriptor {
+            label: None,
+            size: (padded_bytes_per_row * height) as u64,
+            usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        encoder.copy_texture_to_buffer(
+            capture_texture.as_image_copy(),
+            ImageCopyBuffer {
+                buffer: &readback_buffer,
+                layout: ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+
+        self.queue.submit([encoder.finish()]);
+
+        let slice = readback_buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device.poll(Maintain::Wait);
+        receiver
+            .recv()
+            .map_err(|_| StateError::ReadbackChannelClosed)??;
+
+        let padded = slice.get_mapped_range();
+        let mut pixels = Vec::with_capacity((unpadded_bytes_per_row * height) as usize);
+        for row in padded.chunks(padded_bytes_per_row as usize) {
+            pixels.extend_from_slice(&row[..unpadded_bytes_per_row as usize]);
+        }
+        drop(padded);
+        readback_buffer.unmap();
+
+        if matches!(
+            format,
+            TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb
+        ) {
+            for pixel in pixels.chunks_mut(4) {
+                pixel.swap(0, 2);
+            }
+        }
+
+        image::save_buffer(path, &pixels, width, height, image::ColorType::Rgba8)?;
+
+        Ok(())
+    }
+}
+
+/// Inverts the surface/camera mapping to find the grid cell under a cursor position, or
+/// `None` if it falls outside the grid. Pulled out of `State::screen_to_cell` as a pure
+/// function so the picking math can be unit-tested without a GPU device.
+fn pick_cell(
+    position: PhysicalPosition<f64>,
+    size: PhysicalSize<u32>,
+    camera: Camera,
+    grid_size: usize,
+) -> Option<(usize, usize)> {
+    let ndc_x = (position.x / size.width as f64) as f32 * 2.0 - 1.0;
+    let ndc_y = 1.0 - (position.y / size.height as f64) as f32 * 2.0;
+
+    let grid_x = (ndc_x - camera.offset[0]) / camera.zoom;
+    let grid_y = (ndc_y - camera.offset[1]) / camera.zoom;
+
+    let col = ((grid_x + 1.0) / 2.0 * grid_size as f32).floor();
+    let row = ((grid_y + 1.0) / 2.0 * grid_size as f32).floor();
+
+    if col < 0.0 || row < 0.0 || col >= grid_size as f32 || row >= grid_size as f32 {
+        return None;
+    }
+
+    Some((col as usize, row as usize))
+}
+
+fn align_up(value: u32, alignment: u32) -> u32 {
+    (value + alignment - 1) / alignment * alignment
 }
 
 struct App<'a> {
     title: &'static str,
+    config: SimulationConfig,
     state: Option<State<'a>>,
 }
 
@@ -390,7 +1271,7 @@ impl<'a> ApplicationHandler for App<'a> {
         );
         window.request_redraw();
 
-        self.state = Some(State::new(window).unwrap());
+        self.state = Some(State::new(window, self.config.clone()).unwrap());
     }
 
     fn window_event(
@@ -446,7 +1327,85 @@ fn main() {
     event_loop.set_control_flow(ControlFlow::Wait);
     let mut app = App {
         title: "App",
+        config: SimulationConfig::default(),
         state: None,
     };
     event_loop.run_app(&mut app).unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity_camera() -> Camera {
+        Camera {
+            offset: [0.0, 0.0],
+            zoom: 1.0,
+        }
+    }
+
+    #[test]
+    fn pick_cell_maps_corners_to_grid_extremes() {
+        // y is flipped between screen space (down) and NDC (up), and row 0 is drawn at the
+        // bottom, so a cursor near the top-left of the screen lands in column 0 of the last
+        // row (drawn at the top-left), and a cursor near the bottom-right lands in row 0.
+        let size = PhysicalSize::new(100, 100);
+        let camera = identity_camera();
+
+        assert_eq!(
+            pick_cell(PhysicalPosition::new(5.0, 5.0), size, camera, 10),
+            Some((0, 9))
+        );
+        assert_eq!(
+            pick_cell(PhysicalPosition::new(95.0, 95.0), size, camera, 10),
+            Some((9, 0))
+        );
+    }
+
+    #[test]
+    fn pick_cell_round_trips_the_center() {
+        let size = PhysicalSize::new(100, 100);
+        let camera = identity_camera();
+
+        assert_eq!(
+            pick_cell(PhysicalPosition::new(50.0, 50.0), size, camera, 10),
+            Some((5, 5))
+        );
+    }
+
+    #[test]
+    fn pick_cell_rejects_positions_outside_the_grid() {
+        let size = PhysicalSize::new(100, 100);
+        let camera = Camera {
+            offset: [0.5, 0.0],
+            zoom: 1.0,
+        };
+
+        assert_eq!(
+            pick_cell(PhysicalPosition::new(0.0, 50.0), size, camera, 10),
+            None
+        );
+    }
+
+    #[test]
+    fn align_up_rounds_up_to_the_next_multiple() {
+        assert_eq!(align_up(0, 256), 0);
+        assert_eq!(align_up(1, 256), 256);
+        assert_eq!(align_up(256, 256), 256);
+        assert_eq!(align_up(257, 256), 512);
+    }
+
+    #[test]
+    fn conway_rule_matches_bs23() {
+        // Survive on 2 or 3 live neighbors (bits 2 and 3), birth on exactly 3 (bit 3).
+        assert_eq!(Rule::CONWAY.survive, (1 << 2) | (1 << 3));
+        assert_eq!(Rule::CONWAY.birth, 1 << 3);
+
+        for n in 0..=8u32 {
+            let survives = (Rule::CONWAY.survive & (1 << n)) != 0;
+            let born = (Rule::CONWAY.birth & (1 << n)) != 0;
+            assert_eq!(survives, n == 2 || n == 3);
+            assert_eq!(born, n == 3);
+        }
+    }
+}
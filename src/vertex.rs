@@ -3,15 +3,15 @@ use wgpu::{
     VertexBufferLayout,
 };
 
-pub const VERTICES: [f32; 12] = [
+pub const VERTICES: [f32; 8] = [
     -0.8, -0.8, //
     0.8, -0.8, //
     0.8, 0.8, //
-    -0.8, -0.8, //
-    0.8, 0.8, //
     -0.8, 0.8,
 ];
 
+pub const INDICES: [u16; 6] = [0, 1, 2, 0, 2, 3];
+
 pub fn create_vertex_buffer(device: &Device, size: u64) -> Buffer {
     device.create_buffer(&BufferDescriptor {
         label: None,
@@ -21,6 +21,15 @@ pub fn create_vertex_buffer(device: &Device, size: u64) -> Buffer {
     })
 }
 
+pub fn create_index_buffer(device: &Device, size: u64) -> Buffer {
+    device.create_buffer(&BufferDescriptor {
+        label: None,
+        usage: BufferUsages::INDEX | BufferUsages::COPY_DST,
+        size,
+        mapped_at_creation: false,
+    })
+}
+
 const VERTEX_ATTRIBUTES: &[VertexAttribute] = &vertex_attr_array![0 => Float32x2];
 
 pub fn get_vertex_buffer_layout<'a>() -> VertexBufferLayout<'a> {